@@ -3,18 +3,28 @@ use clap::Parser;
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
 struct CmdArgs {
-    /// MAC address to send packet to. Should be in format AA:BB:CC:DD:EE:FF, AA-BB-CC-DD-EE-FF or
-    /// AA/BB/CC/DD/EE/FF.
-    mac_address: String,
+    /// MAC address, or nickname from the registry file, of the device to wake. MAC addresses
+    /// should be in format AA:BB:CC:DD:EE:FF, AA-BB-CC-DD-EE-FF or AABB.CCDD.EEFF.
+    target: String,
+
+    /// Path to a YAML registry file mapping nicknames to hosts.
+    #[clap(short, long)]
+    registry: Option<std::path::PathBuf>,
 }
 
 fn main() -> wakey::Result<()> {
-    let mac_adress = CmdArgs::parse().mac_address;
-    let sep = mac_adress
-        .chars()
-        .find(|ch| *ch == ':' || *ch == '-' || *ch == '/')
-        .expect("Invalid MAC address format. Please use one of the separators: [:, -, /]");
-    let wol = wakey::WolPacket::from_string(&mac_adress, sep)?;
+    let args = CmdArgs::parse();
+
+    if let Some(registry_path) = args.registry {
+        let registry = wakey::registry::HostRegistry::from_path(registry_path)?;
+        if registry.get(&args.target).is_some() {
+            registry.wake(&args.target)?;
+            println!("Sent the magic packet.");
+            return Ok(());
+        }
+    }
+
+    let wol = wakey::WolPacket::from_string(&args.target)?;
     if wol.send_magic().is_ok() {
         println!("Sent the magic packet.");
     } else {
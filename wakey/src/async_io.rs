@@ -0,0 +1,43 @@
+//! Async sending API built on Tokio, enabled by the `tokio` feature.
+//!
+//! Unlike [`send_magic`](crate::WolPacket::send_magic), these variants take
+//! an already-bound [`tokio::net::UdpSocket`] so callers can fire many
+//! packets concurrently, e.g. waking a whole subnet, without rebinding a
+//! socket per send.
+
+use std::net::{Ipv4Addr, SocketAddr};
+
+use tokio::net::{ToSocketAddrs, UdpSocket};
+
+use crate::{Result, WolPacket};
+
+impl WolPacket {
+    /// Async counterpart of [`send_magic`](WolPacket::send_magic).
+    /// # Example
+    /// ```no_run
+    /// # async fn run() -> wakey::Result<()> {
+    /// use tokio::net::UdpSocket;
+    ///
+    /// let wol = wakey::WolPacket::from_string("00:01:02:03:04:05").unwrap();
+    /// let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    /// wol.send_magic_async(&socket).await
+    /// # }
+    /// ```
+    pub async fn send_magic_async(&self, socket: &UdpSocket) -> Result<()> {
+        self.send_magic_to_async(socket, SocketAddr::from((Ipv4Addr::BROADCAST, 9)))
+            .await
+    }
+
+    /// Async counterpart of [`send_magic_to`](WolPacket::send_magic_to),
+    /// sending over an already-bound `socket`.
+    pub async fn send_magic_to_async<A: ToSocketAddrs>(
+        &self,
+        socket: &UdpSocket,
+        dst: A,
+    ) -> Result<()> {
+        socket.set_broadcast(true)?;
+        socket.send_to(&self.packet, dst).await?;
+
+        Ok(())
+    }
+}
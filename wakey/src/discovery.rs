@@ -0,0 +1,221 @@
+//! LAN discovery: find wakeable devices and their MAC addresses.
+//!
+//! A client broadcasts a small request carrying a random idempotency token;
+//! responders running [`serve_discovery`] reply with their MAC address and
+//! an optional nickname. The client collects replies for a bounded window
+//! and returns the distinct peers that answered.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::io::ErrorKind;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+
+use crate::{MacAddr, Result, MAC_SIZE};
+
+const TOKEN_LEN: usize = 8;
+const REQUEST_TAG: u8 = 0x01;
+const RESPONSE_TAG: u8 = 0x02;
+/// `tag + token + mac + nickname length byte + longest possible nickname`.
+const MAX_DATAGRAM_LEN: usize = 1 + TOKEN_LEN + MAC_SIZE + 1 + u8::MAX as usize;
+
+type Token = [u8; TOKEN_LEN];
+
+/// A device discovered by [`discover`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredPeer {
+    /// Address the response was received from.
+    pub addr: SocketAddr,
+    /// MAC address reported by the peer.
+    pub mac: MacAddr,
+    /// Human-readable nickname reported by the peer, if any.
+    pub nickname: Option<String>,
+}
+
+/// Broadcasts a discovery request to `broadcast_addr` and collects replies
+/// for `timeout`. Replies that do not carry this request's idempotency token
+/// are ignored, and only the first reply from each remote address is kept.
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+///
+/// let peers = wakey::discovery::discover("255.255.255.255:9999", Duration::from_secs(2)).unwrap();
+/// for peer in peers {
+///     println!("{} at {}", peer.mac, peer.addr);
+/// }
+/// ```
+pub fn discover<A: ToSocketAddrs>(
+    broadcast_addr: A,
+    timeout: Duration,
+) -> Result<Vec<DiscoveredPeer>> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+    socket.set_broadcast(true)?;
+
+    let token = random_token();
+    socket.send_to(&encode_request(&token), broadcast_addr)?;
+
+    let mut peers: HashMap<SocketAddr, DiscoveredPeer> = HashMap::new();
+    let deadline = Instant::now() + timeout;
+    let mut buf = [0u8; MAX_DATAGRAM_LEN];
+
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        socket.set_read_timeout(Some(remaining))?;
+
+        let (len, addr) = match socket.recv_from(&mut buf) {
+            Ok(result) => result,
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Some((mac, nickname)) = decode_response(&buf[..len], &token) {
+            peers.entry(addr).or_insert(DiscoveredPeer {
+                addr,
+                mac,
+                nickname,
+            });
+        }
+    }
+
+    Ok(peers.into_values().collect())
+}
+
+/// Listens on `addr` and answers every discovery request with this host's
+/// `mac` and optional `nickname`. Runs until an I/O error occurs.
+/// # Example
+/// ```no_run
+/// use wakey::MacAddr;
+///
+/// let mac = MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05);
+/// wakey::discovery::serve_discovery("0.0.0.0:9999", mac, Some("nas")).unwrap();
+/// ```
+pub fn serve_discovery<A: ToSocketAddrs>(
+    addr: A,
+    mac: MacAddr,
+    nickname: Option<&str>,
+) -> Result<()> {
+    let socket = UdpSocket::bind(addr)?;
+    let mut buf = [0u8; MAX_DATAGRAM_LEN];
+
+    loop {
+        let (len, src) = socket.recv_from(&mut buf)?;
+
+        if let Some(token) = decode_request(&buf[..len]) {
+            socket.send_to(&encode_response(&token, mac, nickname), src)?;
+        }
+    }
+}
+
+fn random_token() -> Token {
+    RandomState::new().build_hasher().finish().to_ne_bytes()
+}
+
+fn encode_request(token: &Token) -> Vec<u8> {
+    let mut request = Vec::with_capacity(1 + TOKEN_LEN);
+    request.push(REQUEST_TAG);
+    request.extend_from_slice(token);
+    request
+}
+
+fn decode_request(data: &[u8]) -> Option<Token> {
+    if data.len() != 1 + TOKEN_LEN || data[0] != REQUEST_TAG {
+        return None;
+    }
+
+    Token::try_from(&data[1..]).ok()
+}
+
+fn encode_response(token: &Token, mac: MacAddr, nickname: Option<&str>) -> Vec<u8> {
+    let nickname = nickname.unwrap_or_default().as_bytes();
+    let nickname = &nickname[..nickname.len().min(u8::MAX as usize)];
+
+    let mut response = Vec::with_capacity(1 + TOKEN_LEN + MAC_SIZE + 1 + nickname.len());
+    response.push(RESPONSE_TAG);
+    response.extend_from_slice(token);
+    response.extend_from_slice(mac.as_bytes());
+    response.push(nickname.len() as u8);
+    response.extend_from_slice(nickname);
+    response
+}
+
+fn decode_response(data: &[u8], expected_token: &Token) -> Option<(MacAddr, Option<String>)> {
+    let min_len = 1 + TOKEN_LEN + MAC_SIZE + 1;
+    if data.len() < min_len || data[0] != RESPONSE_TAG {
+        return None;
+    }
+
+    let token = &data[1..1 + TOKEN_LEN];
+    if token != expected_token {
+        return None;
+    }
+
+    let mac = MacAddr::try_from(&data[1 + TOKEN_LEN..1 + TOKEN_LEN + MAC_SIZE]).ok()?;
+
+    let nickname_len = data[1 + TOKEN_LEN + MAC_SIZE] as usize;
+    let nickname_start = min_len;
+    let nickname_bytes = data.get(nickname_start..nickname_start + nickname_len)?;
+    let nickname = (!nickname_bytes.is_empty())
+        .then(|| String::from_utf8(nickname_bytes.to_vec()).ok())
+        .flatten();
+
+    Some((mac, nickname))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_round_trips() {
+        let token = random_token();
+        let encoded = encode_request(&token);
+
+        assert_eq!(decode_request(&encoded), Some(token));
+    }
+
+    #[test]
+    fn response_round_trips_with_nickname() {
+        let token = random_token();
+        let mac = MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05);
+        let encoded = encode_response(&token, mac, Some("nas"));
+
+        let (decoded_mac, nickname) = decode_response(&encoded, &token).unwrap();
+        assert_eq!(decoded_mac, mac);
+        assert_eq!(nickname.as_deref(), Some("nas"));
+    }
+
+    #[test]
+    fn response_round_trips_without_nickname() {
+        let token = random_token();
+        let mac = MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05);
+        let encoded = encode_response(&token, mac, None);
+
+        let (decoded_mac, nickname) = decode_response(&encoded, &token).unwrap();
+        assert_eq!(decoded_mac, mac);
+        assert_eq!(nickname, None);
+    }
+
+    #[test]
+    fn response_round_trips_with_max_length_nickname() {
+        let token = random_token();
+        let mac = MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05);
+        let nickname = "n".repeat(u8::MAX as usize);
+        let encoded = encode_response(&token, mac, Some(&nickname));
+
+        assert!(encoded.len() <= MAX_DATAGRAM_LEN);
+
+        let (decoded_mac, decoded_nickname) = decode_response(&encoded, &token).unwrap();
+        assert_eq!(decoded_mac, mac);
+        assert_eq!(decoded_nickname.as_deref(), Some(nickname.as_str()));
+    }
+
+    #[test]
+    fn response_with_mismatched_token_is_ignored() {
+        let token = random_token();
+        let mut other_token = token;
+        other_token[0] ^= 0xFF;
+
+        let encoded = encode_response(&other_token, MacAddr::nil(), None);
+        assert_eq!(decode_response(&encoded, &token), None);
+    }
+}
@@ -1,7 +1,7 @@
 //! Library for managing Wake-on-LAN packets.
 //! # Example
 //! ```
-//! let wol = wakey::WolPacket::from_string("01:02:03:04:05:06", ':').unwrap();
+//! let wol = wakey::WolPacket::from_string("01:02:03:04:05:06").unwrap();
 //! if wol.send_magic().is_ok() {
 //!     println!("Sent the magic packet!");
 //! } else {
@@ -9,19 +9,32 @@
 //! }
 //! ```
 
+#[cfg(feature = "tokio")]
+mod async_io;
+pub mod discovery;
+mod mac;
+#[cfg(feature = "registry")]
+pub mod registry;
+
 use std::error::Error;
-use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6, ToSocketAddrs, UdpSocket};
 use std::{fmt, iter};
 
 use arrayvec::ArrayVec;
 
+pub use mac::MacAddr;
+
 const MAC_SIZE: usize = 6;
 const MAC_PER_MAGIC: usize = 16;
 const HEADER: [u8; 6] = [0xFF; 6];
 const PACKET_LEN: usize = HEADER.len() + MAC_SIZE * MAC_PER_MAGIC;
+/// The SecureOn password, appended after the MAC repetitions, is either 4 or 6 bytes.
+const MAX_PASSWORD_LEN: usize = 6;
+const MAX_PACKET_LEN: usize = PACKET_LEN + MAX_PASSWORD_LEN;
+/// The IPv6 all-nodes link-local multicast group.
+const ALL_NODES_MULTICAST: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
 
-type Packet = ArrayVec<u8, PACKET_LEN>;
-type Mac = ArrayVec<u8, MAC_SIZE>;
+type Packet = ArrayVec<u8, MAX_PACKET_LEN>;
 
 /// Wrapper `Result` for the module errors.
 pub type Result<T> = std::result::Result<T, WakeyError>;
@@ -33,6 +46,16 @@ pub enum WakeyError {
     InvalidMacLength,
     /// The provided MAC address has invalid format.
     InvalidMacFormat,
+    /// The provided bytes are not a valid magic packet.
+    InvalidPacket,
+    /// The provided SecureOn password is neither 4 nor 6 bytes long.
+    InvalidPasswordLength,
+    /// No host is registered under the given name.
+    #[cfg(feature = "registry")]
+    UnknownHost(String),
+    /// The registry file could not be loaded or parsed.
+    #[cfg(feature = "registry")]
+    InvalidRegistry(String),
     /// There was an error sending the WoL packet.
     SendFailure(std::io::Error),
 }
@@ -46,6 +69,14 @@ impl fmt::Display for WakeyError {
                 write!(f, "Invalid MAC address length")
             }
             WakeyError::InvalidMacFormat => write!(f, "Invalid MAC address format"),
+            WakeyError::InvalidPacket => write!(f, "Invalid magic packet"),
+            WakeyError::InvalidPasswordLength => {
+                write!(f, "SecureOn password must be 4 or 6 bytes long")
+            }
+            #[cfg(feature = "registry")]
+            WakeyError::UnknownHost(name) => write!(f, "No host registered as '{name}'"),
+            #[cfg(feature = "registry")]
+            WakeyError::InvalidRegistry(e) => write!(f, "Couldn't load host registry: {e}"),
             WakeyError::SendFailure(e) => write!(f, "Couldn't send WoL packet: {e}"),
         }
     }
@@ -76,15 +107,111 @@ impl WolPacket {
         })
     }
 
-    /// Creates WOL packet from string MAC representation (e.x. 00:01:02:03:04:05)
+    /// Creates WOL packet from a [`MacAddr`].
+    /// # Example
+    /// ```
+    /// use wakey::MacAddr;
+    /// let wol = wakey::WolPacket::from_mac(MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05)).unwrap();
+    /// ```
+    pub fn from_mac(mac: MacAddr) -> Result<WolPacket> {
+        WolPacket::from_bytes(mac.as_ref())
+    }
+
+    /// Creates WOL packet from string MAC representation, auto-detecting the
+    /// `:`, `-` and `.` separators (e.x. `00:01:02:03:04:05`).
+    /// # Example
+    /// ```
+    /// let wol = wakey::WolPacket::from_string("00:01:02:03:04:05").unwrap();
+    /// ```
+    pub fn from_string<T: AsRef<str>>(data: T) -> Result<WolPacket> {
+        WolPacket::from_mac(data.as_ref().parse::<MacAddr>()?)
+    }
+
+    /// Creates a WOL packet carrying a SecureOn password, appended after the
+    /// MAC repetitions. `password` must be exactly 4 or 6 bytes.
+    /// # Example
+    /// ```
+    /// let wol = wakey::WolPacket::from_bytes_with_password(
+    ///     &[0x00, 0x01, 0x02, 0x03, 0x04, 0x05],
+    ///     &[0x01, 0x02, 0x03, 0x04],
+    /// );
+    /// ```
+    pub fn from_bytes_with_password(mac: &[u8], password: &[u8]) -> Result<WolPacket> {
+        if password.len() != 4 && password.len() != 6 {
+            return Err(WakeyError::InvalidPasswordLength);
+        }
+
+        let mut packet = WolPacket::create_packet_bytes(mac)?;
+        packet.extend(password.iter().copied());
+
+        Ok(WolPacket { packet })
+    }
+
+    /// Creates a WOL packet with a SecureOn password from a string MAC
+    /// representation (see [`from_string`](WolPacket::from_string) for the
+    /// accepted separators).
+    pub fn from_string_with_password<T: AsRef<str>>(data: T, password: &[u8]) -> Result<WolPacket> {
+        let mac = data.as_ref().parse::<MacAddr>()?;
+        WolPacket::from_bytes_with_password(mac.as_ref(), password)
+    }
+
+    /// Parses a received magic packet, checking the 6-byte `0xFF` header and
+    /// that all 16 MAC repetitions agree.
     /// # Example
     /// ```
-    /// let wol = wakey::WolPacket::from_string("00:01:02:03:04:05", ':');
+    /// let wol = wakey::WolPacket::from_string("00:01:02:03:04:05").unwrap();
+    /// let parsed = wakey::WolPacket::parse(&wol.clone().into_inner()).unwrap();
+    /// assert_eq!(wol, parsed);
+    /// ```
+    pub fn parse(data: &[u8]) -> Result<WolPacket> {
+        if data.len() < PACKET_LEN || data[..HEADER.len()] != HEADER[..] {
+            return Err(WakeyError::InvalidPacket);
+        }
+
+        let mac = &data[HEADER.len()..HEADER.len() + MAC_SIZE];
+        let repetitions_agree = data[HEADER.len()..PACKET_LEN]
+            .chunks_exact(MAC_SIZE)
+            .all(|c| c == mac);
+
+        if !repetitions_agree {
+            return Err(WakeyError::InvalidPacket);
+        }
+
+        match &data[PACKET_LEN..] {
+            [] => WolPacket::from_bytes(mac),
+            password @ ([_, _, _, _] | [_, _, _, _, _, _]) => {
+                WolPacket::from_bytes_with_password(mac, password)
+            }
+            _ => Err(WakeyError::InvalidPacket),
+        }
+    }
+
+    /// Returns the target MAC address encoded in this packet.
+    #[must_use]
+    pub fn mac(&self) -> MacAddr {
+        MacAddr::try_from(&self.packet[HEADER.len()..HEADER.len() + MAC_SIZE])
+            .expect("a WolPacket always stores a valid MAC address")
+    }
+
+    /// Returns the SecureOn password carried by this packet, if any.
+    #[must_use]
+    pub fn password(&self) -> Option<&[u8]> {
+        let password = &self.packet[PACKET_LEN..];
+        (!password.is_empty()).then_some(password)
+    }
+
+    /// Binds a UDP socket to `addr`, waits for a single datagram, and decodes
+    /// it as a magic packet.
+    /// # Example
+    /// ```no_run
+    /// let wol = wakey::WolPacket::listen("0.0.0.0:9").unwrap();
+    /// println!("woken by {}", wol.mac());
     /// ```
-    /// # Panic
-    ///  Panics when input MAC is invalid (i.e. contains non-byte characters)
-    pub fn from_string<T: AsRef<str>>(data: T, sep: char) -> Result<WolPacket> {
-        WolPacket::from_bytes(&WolPacket::mac_to_byte(data, sep)?)
+    pub fn listen<A: ToSocketAddrs>(addr: A) -> Result<WolPacket> {
+        let socket = UdpSocket::bind(addr)?;
+        let mut buf = [0u8; MAX_PACKET_LEN];
+        let (len, _) = socket.recv_from(&mut buf)?;
+        WolPacket::parse(&buf[..len])
     }
 
     /// Broadcasts the magic packet from / to default address
@@ -119,39 +246,33 @@ impl WolPacket {
         Ok(())
     }
 
+    /// Sends the magic packet to the IPv6 all-nodes link-local multicast
+    /// group (`ff02::1`) over the network interface identified by
+    /// `interface_index`, for WoL on IPv6-only or dual-stack LANs.
+    /// # Example
+    /// ```no_run
+    /// let wol = wakey::WolPacket::from_string("00:01:02:03:04:05").unwrap();
+    /// wol.send_magic_ipv6(0).unwrap();
+    /// ```
+    pub fn send_magic_ipv6(&self, interface_index: u32) -> Result<()> {
+        let socket = UdpSocket::bind(SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)))?;
+        socket.set_multicast_loop_v6(false)?;
+
+        let dst = SocketAddrV6::new(ALL_NODES_MULTICAST, 9, 0, interface_index);
+        socket.send_to(&self.packet, dst)?;
+
+        Ok(())
+    }
+
     /// Returns the underlying WoL packet bytes
     #[must_use]
     pub fn into_inner(self) -> Packet {
         self.packet
     }
 
-    /// Converts string representation of MAC address (e.x. 00:01:02:03:04:05) to raw bytes.
-    /// # Panic
-    /// Panics when input MAC is invalid (i.e. contains non-byte characters)
-    fn mac_to_byte<T: AsRef<str>>(data: T, sep: char) -> Result<Mac> {
-        // hex-encoded bytes * 2 plus separators
-        if data.as_ref().len() != MAC_SIZE * 3 - 1 {
-            return Err(WakeyError::InvalidMacLength);
-        }
-
-        let bytes = data
-            .as_ref()
-            .split(sep)
-            .map(|x| hex::decode(x).map_err(|_| WakeyError::InvalidMacFormat))
-            .collect::<Result<ArrayVec<_, MAC_SIZE>>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Mac>();
-
-        debug_assert_eq!(MAC_SIZE, bytes.len());
-
-        Ok(bytes)
-    }
-
     /// Extends the MAC address to fill the magic packet
     fn extend_mac(mac: &[u8]) -> ArrayVec<u8, { MAC_SIZE * MAC_PER_MAGIC }> {
-        let magic = iter::repeat(mac)
-            .take(MAC_PER_MAGIC)
+        let magic = iter::repeat_n(mac, MAC_PER_MAGIC)
             .flatten()
             .copied()
             .collect::<ArrayVec<u8, { MAC_SIZE * MAC_PER_MAGIC }>>();
@@ -206,58 +327,110 @@ mod tests {
     }
 
     #[test]
-    fn mac_to_byte_test() {
-        let mac = "01:02:03:04:05:06";
-        let result = WolPacket::mac_to_byte(mac, ':');
-
+    fn from_string_parses_via_mac_addr() {
+        let wol = WolPacket::from_string("01:02:03:04:05:06").unwrap();
         assert_eq!(
-            result.unwrap().into_inner().unwrap(),
-            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+            wol,
+            WolPacket::from_bytes(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06]).unwrap()
         );
     }
 
     #[test]
-    fn mac_to_byte_invalid_chars_test() {
-        let mac = "ZZ:02:03:04:05:06";
+    fn from_string_invalid_mac_is_rejected() {
         assert!(matches!(
-            WolPacket::mac_to_byte(mac, ':'),
+            WolPacket::from_string("ZZ:02:03:04:05:06"),
             Err(WakeyError::InvalidMacFormat)
         ));
     }
 
     #[test]
-    fn mac_to_byte_invalid_separator_test() {
-        let mac = "01002:03:04:05:06";
+    fn create_packet_bytes_test() {
+        let bytes = WolPacket::create_packet_bytes(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap();
+
+        assert_eq!(bytes.len(), MAC_SIZE * MAC_PER_MAGIC + HEADER.len());
+        assert!(bytes.iter().all(|&x| x == 0xFF));
+    }
+
+    #[test]
+    fn parse_round_trips_a_built_packet() {
+        let wol = WolPacket::from_string("00:01:02:03:04:05").unwrap();
+        let parsed = WolPacket::parse(&wol.clone().into_inner()).unwrap();
+
+        assert_eq!(wol, parsed);
+        assert_eq!(
+            parsed.mac(),
+            "00:01:02:03:04:05".parse::<MacAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_wrong_length() {
+        assert!(matches!(
+            WolPacket::parse(&[0xFF; HEADER.len()]),
+            Err(WakeyError::InvalidPacket)
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_bad_header() {
+        let mut bytes = WolPacket::from_string("00:01:02:03:04:05")
+            .unwrap()
+            .into_inner();
+        bytes[0] = 0x00;
+
         assert!(matches!(
-            WolPacket::mac_to_byte(mac, ':'),
-            Err(WakeyError::InvalidMacFormat)
+            WolPacket::parse(&bytes),
+            Err(WakeyError::InvalidPacket)
         ));
     }
 
     #[test]
-    fn mac_to_byte_mac_too_long_test() {
-        let mac = "01:02:03:04:05:06:07";
+    fn parse_rejects_mismatched_repetitions() {
+        let mut bytes = WolPacket::from_string("00:01:02:03:04:05")
+            .unwrap()
+            .into_inner();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+
         assert!(matches!(
-            WolPacket::mac_to_byte(mac, ':'),
-            Err(WakeyError::InvalidMacLength)
+            WolPacket::parse(&bytes),
+            Err(WakeyError::InvalidPacket)
         ));
     }
 
     #[test]
-    fn mac_to_byte_mac_too_short_test() {
-        let mac = "01:02:03:04:05";
+    fn from_bytes_with_password_appends_password() {
+        let mac = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+        let wol = WolPacket::from_bytes_with_password(&mac, &[0xAA, 0xBB, 0xCC, 0xDD]).unwrap();
+
+        assert_eq!(wol.password(), Some(&[0xAA, 0xBB, 0xCC, 0xDD][..]));
+        assert_eq!(wol.mac(), MacAddr::try_from(&mac[..]).unwrap());
+    }
+
+    #[test]
+    fn from_bytes_with_password_rejects_bad_length() {
+        let mac = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
         assert!(matches!(
-            WolPacket::mac_to_byte(mac, ':'),
-            Err(WakeyError::InvalidMacLength)
+            WolPacket::from_bytes_with_password(&mac, &[0xAA, 0xBB]),
+            Err(WakeyError::InvalidPasswordLength)
         ));
     }
 
     #[test]
-    fn create_packet_bytes_test() {
-        let bytes = WolPacket::create_packet_bytes(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap();
+    fn parse_surfaces_the_password() {
+        let mac = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+        let password = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let wol = WolPacket::from_bytes_with_password(&mac, &password).unwrap();
+
+        let parsed = WolPacket::parse(&wol.clone().into_inner()).unwrap();
+        assert_eq!(parsed, wol);
+        assert_eq!(parsed.password(), Some(&password[..]));
+    }
 
-        assert_eq!(bytes.len(), MAC_SIZE * MAC_PER_MAGIC + HEADER.len());
-        assert!(bytes.iter().all(|&x| x == 0xFF));
+    #[test]
+    fn packet_without_password_has_none() {
+        let wol = WolPacket::from_string("00:01:02:03:04:05").unwrap();
+        assert_eq!(wol.password(), None);
     }
 
     #[test]
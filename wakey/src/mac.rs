@@ -0,0 +1,272 @@
+//! A dedicated MAC address type with multi-format parsing and validation.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Result, WakeyError, MAC_SIZE};
+
+/// An IEEE 802 MAC address (EUI-48).
+///
+/// # Example
+/// ```
+/// use wakey::MacAddr;
+///
+/// let mac: MacAddr = "00:01:02:03:04:05".parse().unwrap();
+/// assert_eq!(mac, MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MacAddr([u8; MAC_SIZE]);
+
+impl MacAddr {
+    /// Creates a `MacAddr` from its six octets.
+    #[must_use]
+    pub fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> MacAddr {
+        MacAddr([a, b, c, d, e, f])
+    }
+
+    /// The all-zero MAC address (`00:00:00:00:00:00`).
+    #[must_use]
+    pub fn nil() -> MacAddr {
+        MacAddr([0; MAC_SIZE])
+    }
+
+    /// The broadcast MAC address (`FF:FF:FF:FF:FF:FF`).
+    #[must_use]
+    pub fn broadcast() -> MacAddr {
+        MacAddr([0xFF; MAC_SIZE])
+    }
+
+    /// Returns `true` if this is the all-zero address.
+    #[must_use]
+    pub fn is_nil(&self) -> bool {
+        self.0 == [0; MAC_SIZE]
+    }
+
+    /// Returns `true` if this is the broadcast address.
+    #[must_use]
+    pub fn is_broadcast(&self) -> bool {
+        self.0 == [0xFF; MAC_SIZE]
+    }
+
+    /// Returns `true` if the I/G bit (the low bit of the first octet) is set,
+    /// marking this as a multicast address.
+    #[must_use]
+    pub fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Returns `true` if this is a unicast address.
+    ///
+    /// The complement of [`is_multicast`](MacAddr::is_multicast).
+    #[must_use]
+    pub fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Returns the address as its six raw octets.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; MAC_SIZE] {
+        &self.0
+    }
+
+    /// Expands this EUI-48 address into modified EUI-64 form, as used to
+    /// derive IPv6 interface identifiers (RFC 4291): `FF:FE` is inserted
+    /// between the OUI and the device identifier, and the universal/local
+    /// bit (bit 1 of the first octet) is flipped.
+    #[must_use]
+    pub fn to_eui64(&self) -> [u8; 8] {
+        let [a, b, c, d, e, f] = self.0;
+        [a ^ 0x02, b, c, 0xFF, 0xFE, d, e, f]
+    }
+
+    /// Parses a MAC address from a hex string with no separators, e.g.
+    /// `"000102030405"`.
+    fn from_hex(hex: &str) -> Result<MacAddr> {
+        if hex.len() != MAC_SIZE * 2 {
+            return Err(WakeyError::InvalidMacLength);
+        }
+
+        let bytes = hex::decode(hex).map_err(|_| WakeyError::InvalidMacFormat)?;
+
+        let mut octets = [0u8; MAC_SIZE];
+        octets.copy_from_slice(&bytes);
+        Ok(MacAddr(octets))
+    }
+
+    /// Parses a MAC address from groups of hex digits separated by `sep`,
+    /// requiring exactly `group_count` groups of `group_width` hex digits
+    /// each (e.g. 6 groups of 2 for `:`/`-`, or 3 groups of 4 for `.`).
+    fn from_grouped_hex(
+        s: &str,
+        sep: char,
+        group_count: usize,
+        group_width: usize,
+    ) -> Result<MacAddr> {
+        let groups: Vec<&str> = s.split(sep).collect();
+        if groups.len() != group_count || groups.iter().any(|g| g.len() != group_width) {
+            return Err(WakeyError::InvalidMacLength);
+        }
+
+        MacAddr::from_hex(&groups.concat())
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = WakeyError;
+
+    /// Parses a MAC address, auto-detecting the `:`, `-` and `.` separators
+    /// (the latter for dotted-triplet notation, e.x. `0001.0203.0405`), and
+    /// also accepting the bare 12-hex-digit form.
+    ///
+    /// The `:` and `-` forms must have exactly 6 groups of 2 hex digits, and
+    /// the `.` form must have exactly 3 groups of 4 hex digits; anything else
+    /// (wrong group count, mismatched group widths, stray separators) is
+    /// rejected rather than silently concatenated.
+    fn from_str(s: &str) -> Result<MacAddr> {
+        if let Some(sep) = [':', '-'].into_iter().find(|&sep| s.contains(sep)) {
+            return MacAddr::from_grouped_hex(s, sep, MAC_SIZE, 2);
+        }
+
+        if s.contains('.') {
+            return MacAddr::from_grouped_hex(s, '.', 3, 4);
+        }
+
+        MacAddr::from_hex(s)
+    }
+}
+
+impl From<[u8; MAC_SIZE]> for MacAddr {
+    fn from(octets: [u8; MAC_SIZE]) -> MacAddr {
+        MacAddr(octets)
+    }
+}
+
+impl TryFrom<&[u8]> for MacAddr {
+    type Error = WakeyError;
+
+    fn try_from(bytes: &[u8]) -> Result<MacAddr> {
+        if bytes.len() != MAC_SIZE {
+            return Err(WakeyError::InvalidMacLength);
+        }
+
+        let mut octets = [0u8; MAC_SIZE];
+        octets.copy_from_slice(bytes);
+        Ok(MacAddr(octets))
+    }
+}
+
+impl AsRef<[u8]> for MacAddr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "registry")]
+impl<'de> serde::Deserialize<'de> for MacAddr {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_colon_separated() {
+        let mac: MacAddr = "00:01:02:03:04:05".parse().unwrap();
+        assert_eq!(mac, MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05));
+    }
+
+    #[test]
+    fn parse_dash_separated() {
+        let mac: MacAddr = "00-01-02-03-04-05".parse().unwrap();
+        assert_eq!(mac, MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05));
+    }
+
+    #[test]
+    fn parse_dotted_triplets() {
+        let mac: MacAddr = "0001.0203.0405".parse().unwrap();
+        assert_eq!(mac, MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05));
+    }
+
+    #[test]
+    fn parse_bare_hex() {
+        let mac: MacAddr = "000102030405".parse().unwrap();
+        assert_eq!(mac, MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05));
+    }
+
+    #[test]
+    fn parse_invalid_chars() {
+        assert!(matches!(
+            "ZZ:01:02:03:04:05".parse::<MacAddr>(),
+            Err(WakeyError::InvalidMacFormat)
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_length() {
+        assert!(matches!(
+            "00:01:02:03:04".parse::<MacAddr>(),
+            Err(WakeyError::InvalidMacLength)
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_groups() {
+        for input in [
+            ":010203040506",
+            "00:0102:03:04:05",
+            "0001:0203:0405",
+            "00:0102030405",
+        ] {
+            assert!(
+                matches!(input.parse::<MacAddr>(), Err(WakeyError::InvalidMacLength)),
+                "expected {input:?} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn display_formats_lowercase_colon_separated() {
+        let mac = MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05);
+        assert_eq!(mac.to_string(), "00:01:02:03:04:05");
+    }
+
+    #[test]
+    fn nil_and_broadcast() {
+        assert!(MacAddr::nil().is_nil());
+        assert!(MacAddr::broadcast().is_broadcast());
+    }
+
+    #[test]
+    fn multicast_and_unicast() {
+        let multicast = MacAddr::new(0x01, 0, 0, 0, 0, 0);
+        let unicast = MacAddr::new(0x00, 0, 0, 0, 0, 0);
+
+        assert!(multicast.is_multicast());
+        assert!(!multicast.is_unicast());
+        assert!(unicast.is_unicast());
+        assert!(!unicast.is_multicast());
+    }
+
+    #[test]
+    fn to_eui64_inserts_ff_fe_and_flips_ul_bit() {
+        let mac = MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05);
+        assert_eq!(
+            mac.to_eui64(),
+            [0x02, 0x01, 0x02, 0xFF, 0xFE, 0x03, 0x04, 0x05]
+        );
+    }
+}
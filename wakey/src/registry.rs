@@ -0,0 +1,115 @@
+//! A YAML-backed registry mapping host nicknames to MAC addresses, enabled
+//! by the `registry` feature.
+
+use std::collections::HashMap;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{MacAddr, Result, WakeyError, WolPacket};
+
+/// A single registered host.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HostEntry {
+    /// MAC address to wake.
+    pub mac: MacAddr,
+    /// Destination to broadcast the magic packet to, e.g. `192.168.1.255:9`.
+    /// Defaults to `255.255.255.255:9`.
+    #[serde(default)]
+    pub broadcast: Option<String>,
+}
+
+/// A registry of human-friendly host nicknames, loaded from a YAML file
+/// mapping each nickname to a [`HostEntry`].
+/// # Example
+/// ```no_run
+/// let registry = wakey::registry::HostRegistry::from_path("hosts.yaml").unwrap();
+/// registry.wake("nas").unwrap();
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct HostRegistry {
+    hosts: HashMap<String, HostEntry>,
+}
+
+impl HostRegistry {
+    /// Loads a registry from a YAML file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<HostRegistry> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| WakeyError::InvalidRegistry(e.to_string()))?;
+
+        serde_yaml::from_str(&contents).map_err(|e| WakeyError::InvalidRegistry(e.to_string()))
+    }
+
+    /// Returns the entry registered under `name`, if any.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&HostEntry> {
+        self.hosts.get(name)
+    }
+
+    /// Returns the nicknames of every registered host.
+    pub fn list(&self) -> impl Iterator<Item = &str> {
+        self.hosts.keys().map(String::as_str)
+    }
+
+    /// Builds and sends the magic packet for the host registered under `name`.
+    pub fn wake(&self, name: &str) -> Result<()> {
+        let entry = self
+            .get(name)
+            .ok_or_else(|| WakeyError::UnknownHost(name.to_owned()))?;
+
+        let wol = WolPacket::from_mac(entry.mac)?;
+
+        match &entry.broadcast {
+            Some(dst) => {
+                let dst: SocketAddr = dst
+                    .parse()
+                    .map_err(|_| WakeyError::InvalidRegistry(format!("invalid address: {dst}")))?;
+                wol.send_magic_to(SocketAddr::from(([0, 0, 0, 0], 0)), dst)
+            }
+            None => wol.send_magic(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_hosts_and_looks_up_by_nickname() {
+        let yaml = "
+nas:
+  mac: \"00:01:02:03:04:05\"
+desktop:
+  mac: \"AA-BB-CC-DD-EE-FF\"
+  broadcast: \"192.168.1.255:9\"
+";
+        let registry: HostRegistry = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(
+            registry.get("nas").unwrap().mac,
+            MacAddr::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05)
+        );
+        assert_eq!(
+            registry.get("desktop").unwrap().broadcast.as_deref(),
+            Some("192.168.1.255:9")
+        );
+        assert!(registry.get("unknown").is_none());
+
+        let mut names: Vec<_> = registry.list().collect();
+        names.sort_unstable();
+        assert_eq!(names, ["desktop", "nas"]);
+    }
+
+    #[test]
+    fn wake_unknown_host_is_an_error() {
+        let registry: HostRegistry = serde_yaml::from_str("{}").unwrap();
+        assert!(matches!(
+            registry.wake("missing"),
+            Err(WakeyError::UnknownHost(name)) if name == "missing"
+        ));
+    }
+}